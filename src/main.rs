@@ -1,90 +1,236 @@
-use std::{
-    f64::consts::PI,
-    fmt::{Display, Formatter},
-    ops::Range,
-};
+use std::{f64::consts::PI, ops::Range};
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use patternfly_yew::Alert;
 use patternfly_yew::{
-    Card, ChipVariant, FormGroup, InputState, Select, SelectOption, SelectVariant, Slider, Step,
-    TextInput, Type,
+    Button, ButtonVariant, Card, ChipVariant, FormGroup, InputState, Select, SelectOption,
+    SelectVariant, Slider, Step, TextInput, Type,
+};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, Event, FileReader, HtmlAnchorElement, HtmlInputElement, Url};
+use yew::{
+    function_component, html, html_nested, use_effect_with, use_node_ref, use_state, Callback,
+    Html,
 };
-use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
-use yew::{function_component, html, html_nested, use_state, Callback, Html};
-
-#[derive(Clone, PartialEq, Debug, EnumIter, Copy)]
-enum Material {
-    Aluminium,
-    Plastic,
-    Copper,
-    WoodSoft,
-    WoodHard,
-    WoodMdf,
+
+/// Encodes `state` into a compact, URL-safe string suitable for a location
+/// hash.
+fn encode_state(state: &GlobalState) -> Option<String> {
+    let json = serde_json::to_string(state).ok()?;
+    Some(URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Reverses [`encode_state`].
+fn decode_state(encoded: &str) -> Option<GlobalState> {
+    let json = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+    let json = String::from_utf8(json).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Reconstructs state from the current page's location hash, falling back
+/// to [`GlobalState::default`] if there is none or it fails to decode.
+fn state_from_location_hash() -> GlobalState {
+    let hash = web_sys::window()
+        .and_then(|window| window.location().hash().ok())
+        .unwrap_or_default();
+    let encoded = hash.trim_start_matches('#');
+    if encoded.is_empty() {
+        return GlobalState::default();
+    }
+    decode_state(encoded).unwrap_or_default()
+}
+
+/// Triggers a browser download of `contents` as a file named `filename`.
+///
+/// Wraps the usual Blob + object-URL + anchor-click dance so callers can
+/// just hand over a filename, a MIME type and a string.
+fn save_str(filename: &str, mime_type: &str, contents: &str) {
+    let parts = js_sys::Array::new();
+    parts.push(&JsValue::from_str(contents));
+    let mut properties = BlobPropertyBag::new();
+    properties.type_(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &properties)
+        .expect("failed to build blob");
+    let url = Url::create_object_url_with_blob(&blob).expect("failed to create object url");
+
+    let document = web_sys::window()
+        .expect("no window")
+        .document()
+        .expect("no document");
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")
+        .expect("failed to create anchor")
+        .dyn_into()
+        .expect("created element is not an anchor");
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    let _ = Url::revoke_object_url(&url);
+}
+
+/// A single material's cutting parameters: an overall cutting-speed range,
+/// and a feed-per-flute range at each of a few tool-diameter breakpoints.
+///
+/// `feed_table` must be sorted by diameter ascending; [`feed_per_flute`]
+/// interpolates between breakpoints and clamps outside of them.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct MaterialSpec {
+    name: String,
+    cut_speed: Range<f64>,
+    feed_table: Vec<(f64, Range<f64>)>,
+}
+
+/// The set of materials a user can pick from, editable at runtime and
+/// persisted alongside a saved setup so custom materials travel with it.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct MaterialDb {
+    materials: Vec<MaterialSpec>,
 }
 
-impl Material {
-    pub fn cut_speed(&self) -> Range<f64> {
-        match self {
-            Material::Aluminium => 100.0..450.0,
-            Material::Plastic => 200.0..400.0,
-            Material::Copper => 80.0..200.0,
-            Material::WoodSoft => 300.0..600.0,
-            Material::WoodHard => 200.0..450.0,
-            Material::WoodMdf => 200.0..450.0,
+impl MaterialDb {
+    pub fn find(&self, name: &str) -> Option<&MaterialSpec> {
+        self.materials.iter().find(|material| material.name == name)
+    }
+
+    /// Adds `material`, replacing any existing entry with the same name so
+    /// this also serves as the save path for editing a material in place.
+    pub fn upsert(&mut self, material: MaterialSpec) {
+        match self
+            .materials
+            .iter_mut()
+            .find(|existing| existing.name == material.name)
+        {
+            Some(existing) => *existing = material,
+            None => self.materials.push(material),
         }
     }
-    pub fn feed_table(&self) -> &[(f64, Range<f64>)] {
-        match self {
-            Material::Aluminium => &[
-                (4.0, 0.005..0.015),
-                (6.0, 0.015..0.025),
-                (8.0, 0.02..0.03),
-                (10.0, 0.025..0.038),
-                (12.0, 0.03..0.05),
-            ],
-            Material::Plastic => &[
-                (4.0, 0.02..0.05),
-                (6.0, 0.04..0.09),
-                (8.0, 0.04..0.1),
-                (10.0, 0.05..0.15),
-                (12.0, 0.08..0.18),
-            ],
-            Material::Copper => &[
-                (4.0, 0.01..0.02),
-                (6.0, 0.015..0.025),
-                (8.0, 0.03..0.057),
-                (10.0, 0.035..0.065),
-                (12.0, 0.04..0.08),
-            ],
-            Material::WoodSoft => &[
-                (4.0, 0.02..0.04),
-                (6.0, 0.025..0.055),
-                (8.0, 0.037..0.07),
-                (10.0, 0.045..0.085),
-                (12.0, 0.05..0.095),
-            ],
-            Material::WoodHard => &[
-                (4.0, 0.015..0.035),
-                (6.0, 0.02..0.05),
-                (8.0, 0.03..0.065),
-                (10.0, 0.045..0.08),
-                (12.0, 0.05..0.09),
-            ],
-            Material::WoodMdf => &[
-                (4.0, 0.022..0.044),
-                (6.0, 0.0275..0.0605),
-                (8.0, 0.0407..0.077),
-                (10.0, 0.0495..0.0935),
-                (12.0, 0.055..0.105),
+
+    pub fn remove(&mut self, name: &str) {
+        self.materials.retain(|material| material.name != name);
+    }
+}
+
+impl Default for MaterialDb {
+    fn default() -> Self {
+        MaterialDb {
+            materials: vec![
+                MaterialSpec {
+                    name: "Aluminium".to_string(),
+                    cut_speed: 100.0..450.0,
+                    feed_table: vec![
+                        (4.0, 0.005..0.015),
+                        (6.0, 0.015..0.025),
+                        (8.0, 0.02..0.03),
+                        (10.0, 0.025..0.038),
+                        (12.0, 0.03..0.05),
+                    ],
+                },
+                MaterialSpec {
+                    name: "Kunststoff".to_string(),
+                    cut_speed: 200.0..400.0,
+                    feed_table: vec![
+                        (4.0, 0.02..0.05),
+                        (6.0, 0.04..0.09),
+                        (8.0, 0.04..0.1),
+                        (10.0, 0.05..0.15),
+                        (12.0, 0.08..0.18),
+                    ],
+                },
+                MaterialSpec {
+                    name: "Kupfer / Messing".to_string(),
+                    cut_speed: 80.0..200.0,
+                    feed_table: vec![
+                        (4.0, 0.01..0.02),
+                        (6.0, 0.015..0.025),
+                        (8.0, 0.03..0.057),
+                        (10.0, 0.035..0.065),
+                        (12.0, 0.04..0.08),
+                    ],
+                },
+                MaterialSpec {
+                    name: "Holz weich".to_string(),
+                    cut_speed: 300.0..600.0,
+                    feed_table: vec![
+                        (4.0, 0.02..0.04),
+                        (6.0, 0.025..0.055),
+                        (8.0, 0.037..0.07),
+                        (10.0, 0.045..0.085),
+                        (12.0, 0.05..0.095),
+                    ],
+                },
+                MaterialSpec {
+                    name: "Holz hart".to_string(),
+                    cut_speed: 200.0..450.0,
+                    feed_table: vec![
+                        (4.0, 0.015..0.035),
+                        (6.0, 0.02..0.05),
+                        (8.0, 0.03..0.065),
+                        (10.0, 0.045..0.08),
+                        (12.0, 0.05..0.09),
+                    ],
+                },
+                MaterialSpec {
+                    name: "Holz MDF".to_string(),
+                    cut_speed: 200.0..450.0,
+                    feed_table: vec![
+                        (4.0, 0.022..0.044),
+                        (6.0, 0.0275..0.0605),
+                        (8.0, 0.0407..0.077),
+                        (10.0, 0.0495..0.0935),
+                        (12.0, 0.055..0.105),
+                    ],
+                },
             ],
         }
     }
 }
 
-fn feed_per_flute(material: Material, diameter: f64) -> Range<f64> {
-    let table = material.feed_table();
-    let mut iter = table.iter();
+/// Tool-diameter breakpoints offered by the "add/edit material" form, chosen
+/// to match the breakpoints the six built-in materials already use so a
+/// custom material interpolates just like they do instead of returning a
+/// flat, diameter-independent range.
+const FEED_TABLE_BREAKPOINTS: [f64; 5] = [4.0, 6.0, 8.0, 10.0, 12.0];
+
+/// Turns the form's per-breakpoint text rows into a [`MaterialSpec`] feed
+/// table, skipping breakpoints the user left blank. Returns `None` if any
+/// row is half-filled (only one of min/max given) or if no row is filled at
+/// all, since an empty feed table would leave [`feed_per_flute`] with
+/// nothing to interpolate.
+fn feed_table_from_rows(rows: &[(String, String)]) -> Option<Vec<(f64, Range<f64>)>> {
+    let mut table = Vec::new();
+    for (&diameter, (min, max)) in FEED_TABLE_BREAKPOINTS.iter().zip(rows) {
+        match (min.is_empty(), max.is_empty()) {
+            (true, true) => continue,
+            (false, false) => {
+                let min = min.parse::<f64>().ok()?;
+                let max = max.parse::<f64>().ok()?;
+                table.push((diameter, min..max));
+            }
+            _ => return None,
+        }
+    }
+    (!table.is_empty()).then_some(table)
+}
+
+/// Reverses [`feed_table_from_rows`] for prefilling the form when editing an
+/// existing material: one (min, max) string pair per breakpoint, blank where
+/// the material has no entry for that diameter.
+fn feed_table_to_rows(feed_table: &[(f64, Range<f64>)]) -> Vec<(String, String)> {
+    FEED_TABLE_BREAKPOINTS
+        .iter()
+        .map(|diameter| {
+            feed_table
+                .iter()
+                .find(|(d, _)| d == diameter)
+                .map(|(_, feed)| (format!("{}", feed.start), format!("{}", feed.end)))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn feed_per_flute(material: &MaterialSpec, diameter: f64) -> Range<f64> {
+    let mut iter = material.feed_table.iter();
     let mut last_entry = iter.next().expect("material has empty feed table");
     if last_entry.0 >= diameter {
         return last_entry.1.clone();
@@ -106,24 +252,60 @@ fn feed_per_flute(material: Material, diameter: f64) -> Range<f64> {
     }
     last_entry.1.clone()
 }
-impl Display for Material {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Material::Aluminium => f.write_str("Aluminium"),
-            Material::Plastic => f.write_str("Kunststoff"),
-            Material::Copper => f.write_str("Kupfer / Messing"),
-            Material::WoodSoft => f.write_str("Holz weich"),
-            Material::WoodHard => f.write_str("Holz hart"),
-            Material::WoodMdf => f.write_str("Holz MDF"),
+
+fn rpm_range_for(material: &MaterialSpec, diameter: f64) -> Range<f64> {
+    let vc_range = &material.cut_speed;
+    let rpm_min = vc_range.start * 1000.0 / (diameter * PI);
+    let rpm_max = vc_range.end * 1000.0 / (diameter * PI);
+    rpm_min..rpm_max
+}
+
+/// Number of RPM samples generated per diameter row in the CSV sweep.
+const CSV_RPM_STEPS: usize = 3;
+
+/// Builds a printable sweep table: one row per diameter breakpoint in the
+/// material's feed table, times a few RPM steps across that diameter's
+/// usable range.
+fn state_to_csv(state: &GlobalState, material: &MaterialSpec) -> Result<String, String> {
+    if state.diameter_error() || state.flute_count_error() {
+        return Err("Eingaben fehlerhaft".to_string());
+    }
+    let flute_count = state.flute_count() as f64;
+
+    let mut csv = String::from(
+        "Durchmesser (mm);Drehzahl (U/min);Schnittgeschwindigkeit (m/min);\
+         Zahnvorschub min (mm);Zahnvorschub max (mm);\
+         Vorschub min (mm/min);Vorschub max (mm/min)\n",
+    );
+    for &(diameter, _) in &material.feed_table {
+        let rpm_range = rpm_range_for(material, diameter);
+        let feed = feed_per_flute(material, diameter);
+        for step in 0..CSV_RPM_STEPS {
+            let t = step as f64 / (CSV_RPM_STEPS - 1) as f64;
+            let rpm = rpm_range.start + (rpm_range.end - rpm_range.start) * t;
+            let cut_speed = diameter * PI * rpm / 1000.0;
+            let feed_min = rpm * flute_count * feed.start;
+            let feed_max = rpm * flute_count * feed.end;
+            csv.push_str(&format!(
+                "{diameter:.2};{rpm:.0};{cut_speed:.0};{:.3};{:.3};{feed_min:.0};{feed_max:.0}\n",
+                feed.start, feed.end
+            ));
         }
     }
+    Ok(csv)
 }
-#[derive(Clone, PartialEq, Debug, Copy)]
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 struct GlobalState {
-    material: Material,
+    material: String,
     diameter: f64,
+    /// Derived UI state, not part of a machining setup — left out of
+    /// export/import and the permalink so restoring a setup doesn't carry
+    /// over a stale "invalid input" highlight from whoever saved it.
+    #[serde(skip)]
     diameter_error: bool,
     flute_count: u8,
+    #[serde(skip)]
     flute_count_error: bool,
     min_rpm: f64,
     max_rpm: f64,
@@ -133,7 +315,7 @@ struct GlobalState {
 impl Default for GlobalState {
     fn default() -> Self {
         GlobalState {
-            material: Material::WoodSoft,
+            material: "Holz weich".to_string(),
             diameter: 8.0,
             diameter_error: false,
             flute_count: 2,
@@ -146,28 +328,23 @@ impl Default for GlobalState {
 }
 
 impl GlobalState {
-    pub fn material(&self) -> &Material {
+    pub fn material(&self) -> &str {
         &self.material
     }
 
-    pub fn set_material(&mut self, material: Material) {
+    pub fn set_material(&mut self, material: String) {
         self.material = material;
     }
-    pub fn rpm_range(&self) -> Range<f64> {
-        let diameter = self.diameter;
-        let vc_range = self.material.cut_speed();
-        let rpm_min = vc_range.start * 1000.0 / (diameter * PI);
-        let rpm_max = vc_range.end * 1000.0 / (diameter * PI);
-
-        rpm_min..rpm_max
+    pub fn rpm_range(&self, material: &MaterialSpec) -> Range<f64> {
+        rpm_range_for(material, self.diameter)
     }
 
     pub fn cut_speed(&self) -> f64 {
         self.diameter * PI * self.selected_rpm / 1000.0
     }
 
-    pub fn feed_range(&self) -> Range<f64> {
-        let feed_per_flute = feed_per_flute(self.material, self.diameter);
+    pub fn feed_range(&self, material: &MaterialSpec) -> Range<f64> {
+        let feed_per_flute = feed_per_flute(material, self.diameter);
         self.selected_rpm * self.flute_count as f64 * feed_per_flute.start
             ..self.selected_rpm * self.flute_count as f64 * feed_per_flute.end
     }
@@ -218,15 +395,72 @@ impl GlobalState {
     }
 }
 
+/// Everything an export/import round-trip needs to reproduce a setup: the
+/// calculator state plus the material database it refers to, since custom
+/// materials only make sense together with the setup that uses them.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct SavedSetup {
+    state: GlobalState,
+    materials: MaterialDb,
+}
+
 #[function_component]
 fn App() -> Html {
-    let state = use_state(GlobalState::default);
+    let state = use_state(state_from_location_hash);
+    let materials = use_state(MaterialDb::default);
 
-    let on_change_material: Callback<Material> = {
+    {
         let state = state.clone();
-        Callback::from(move |value| {
-            if *state.material() != value {
-                let mut new_state = *state;
+        use_effect_with((*state).clone(), move |state| {
+            if let Some(encoded) = encode_state(state) {
+                if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+                    let url = format!("#{encoded}");
+                    let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+                }
+            }
+            || ()
+        });
+    }
+
+    let material = materials
+        .find(state.material())
+        .or_else(|| materials.materials.first())
+        .cloned()
+        .unwrap_or_else(|| {
+            MaterialDb::default()
+                .materials
+                .into_iter()
+                .next()
+                .expect("MaterialDb::default() always has at least one material")
+        });
+
+    // A permalink or import can reference a material name that isn't in the
+    // current database (e.g. a hash encodes only `state`, not custom
+    // materials); the lookup above silently falls back to the first
+    // material. Snap the stored name to the one actually in use so the
+    // display, the calculation, and the persisted/encoded state agree
+    // instead of drifting apart.
+    {
+        let state = state.clone();
+        let resolved_name = material.name.clone();
+        use_effect_with(
+            (state.material().to_string(), resolved_name),
+            move |(stored_name, resolved_name)| {
+                if stored_name != resolved_name {
+                    let mut new_state = (*state).clone();
+                    new_state.set_material(resolved_name.clone());
+                    state.set(new_state);
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_change_material: Callback<String> = {
+        let state = state.clone();
+        Callback::from(move |value: String| {
+            if state.material() != value {
+                let mut new_state = (*state).clone();
                 new_state.set_material(value);
                 state.set(new_state);
             }
@@ -237,7 +471,7 @@ fn App() -> Html {
         let state = state.clone();
         Callback::from(move |value| {
             if state.selected_rpm() != value {
-                let mut new_state = *state;
+                let mut new_state = (*state).clone();
                 new_state.set_selected_rpm(value);
                 state.set(new_state);
             }
@@ -248,7 +482,7 @@ fn App() -> Html {
         Callback::from(move |value: String| match value.parse::<f64>() {
             Ok(value) => {
                 if state.diameter() != value || state.diameter_error() {
-                    let mut new_state = *state;
+                    let mut new_state = (*state).clone();
                     new_state.set_diameter(value);
                     new_state.set_diameter_error(false);
                     state.set(new_state);
@@ -256,7 +490,7 @@ fn App() -> Html {
             }
             Err(_) => {
                 if !state.diameter_error() {
-                    let mut new_state = *state;
+                    let mut new_state = (*state).clone();
                     new_state.set_diameter_error(true);
                     state.set(new_state);
                 }
@@ -268,7 +502,7 @@ fn App() -> Html {
         Callback::from(move |value: String| match value.parse::<u8>() {
             Ok(value) => {
                 if state.flute_count() != value || state.flute_count_error() {
-                    let mut new_state = *state;
+                    let mut new_state = (*state).clone();
                     new_state.set_flute_count(value);
                     new_state.set_flute_count_error(false);
                     state.set(new_state);
@@ -276,7 +510,7 @@ fn App() -> Html {
             }
             Err(_) => {
                 if !state.flute_count_error() {
-                    let mut new_state = *state;
+                    let mut new_state = (*state).clone();
                     new_state.set_flute_count_error(true);
                     state.set(new_state);
                 }
@@ -284,7 +518,217 @@ fn App() -> Html {
         })
     };
 
-    let rpm_range = state.rpm_range();
+    let on_remove_material = {
+        let state = state.clone();
+        let materials = materials.clone();
+        Callback::from(move |_| {
+            // Refuse to remove the last material: an empty database would
+            // leave nothing for the picker to fall back to.
+            if materials.materials.len() <= 1 {
+                return;
+            }
+            let mut new_materials = (*materials).clone();
+            new_materials.remove(state.material());
+            if let Some(fallback) = new_materials.materials.first() {
+                let mut new_state = (*state).clone();
+                new_state.set_material(fallback.name.clone());
+                state.set(new_state);
+            }
+            materials.set(new_materials);
+        })
+    };
+
+    let new_material_name = use_state(String::default);
+    let new_material_vc_min = use_state(String::default);
+    let new_material_vc_max = use_state(String::default);
+    let new_material_feed_rows =
+        use_state(|| vec![(String::default(), String::default()); FEED_TABLE_BREAKPOINTS.len()]);
+
+    let on_new_material_name = {
+        let new_material_name = new_material_name.clone();
+        Callback::from(move |value: String| new_material_name.set(value))
+    };
+    let on_new_material_vc_min = {
+        let new_material_vc_min = new_material_vc_min.clone();
+        Callback::from(move |value: String| new_material_vc_min.set(value))
+    };
+    let on_new_material_vc_max = {
+        let new_material_vc_max = new_material_vc_max.clone();
+        Callback::from(move |value: String| new_material_vc_max.set(value))
+    };
+
+    let new_material_feed_table = feed_table_from_rows(&new_material_feed_rows);
+    let new_material_error = new_material_name.is_empty()
+        || new_material_vc_min.parse::<f64>().is_err()
+        || new_material_vc_max.parse::<f64>().is_err()
+        || new_material_feed_table.is_none();
+
+    let new_material_feed_rows_inputs = FEED_TABLE_BREAKPOINTS
+        .iter()
+        .enumerate()
+        .map(|(index, diameter)| {
+            let row = new_material_feed_rows[index].clone();
+            let on_min = {
+                let new_material_feed_rows = new_material_feed_rows.clone();
+                Callback::from(move |value: String| {
+                    let mut rows = (*new_material_feed_rows).clone();
+                    rows[index].0 = value;
+                    new_material_feed_rows.set(rows);
+                })
+            };
+            let on_max = {
+                let new_material_feed_rows = new_material_feed_rows.clone();
+                Callback::from(move |value: String| {
+                    let mut rows = (*new_material_feed_rows).clone();
+                    rows[index].1 = value;
+                    new_material_feed_rows.set(rows);
+                })
+            };
+            html! {
+                <>
+                    <TextInput r#type="number" placeholder={format!("Zahnvorschub min ⌀{diameter}")} value={row.0} onchange={on_min}/>
+                    <TextInput r#type="number" placeholder={format!("Zahnvorschub max ⌀{diameter}")} value={row.1} onchange={on_max}/>
+                </>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let on_save_material = {
+        let materials = materials.clone();
+        let new_material_name = new_material_name.clone();
+        let new_material_vc_min = new_material_vc_min.clone();
+        let new_material_vc_max = new_material_vc_max.clone();
+        let new_material_feed_rows = new_material_feed_rows.clone();
+        Callback::from(move |_| {
+            let parsed = (
+                new_material_vc_min.parse::<f64>(),
+                new_material_vc_max.parse::<f64>(),
+                feed_table_from_rows(&new_material_feed_rows),
+            );
+            if let (Ok(vc_min), Ok(vc_max), Some(feed_table)) = parsed {
+                if !new_material_name.is_empty() {
+                    let mut new_materials = (*materials).clone();
+                    new_materials.upsert(MaterialSpec {
+                        name: (*new_material_name).clone(),
+                        cut_speed: vc_min..vc_max,
+                        feed_table,
+                    });
+                    materials.set(new_materials);
+                    new_material_name.set(String::default());
+                    new_material_vc_min.set(String::default());
+                    new_material_vc_max.set(String::default());
+                    new_material_feed_rows
+                        .set(vec![(String::default(), String::default()); FEED_TABLE_BREAKPOINTS.len()]);
+                }
+            }
+        })
+    };
+
+    let on_edit_material = {
+        let material = material.clone();
+        let new_material_name = new_material_name.clone();
+        let new_material_vc_min = new_material_vc_min.clone();
+        let new_material_vc_max = new_material_vc_max.clone();
+        let new_material_feed_rows = new_material_feed_rows.clone();
+        Callback::from(move |_| {
+            new_material_name.set(material.name.clone());
+            new_material_vc_min.set(format!("{}", material.cut_speed.start));
+            new_material_vc_max.set(format!("{}", material.cut_speed.end));
+            new_material_feed_rows.set(feed_table_to_rows(&material.feed_table));
+        })
+    };
+
+    let import_input_ref = use_node_ref();
+
+    let on_copy_link = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            let Some(encoded) = encode_state(&state) else {
+                return;
+            };
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+            let Ok(href) = window.location().href() else {
+                return;
+            };
+            let base = href.split('#').next().unwrap_or(&href);
+            let url = format!("{base}#{encoded}");
+            let _ = window.navigator().clipboard().write_text(&url);
+        })
+    };
+
+    let on_export = {
+        let state = state.clone();
+        let materials = materials.clone();
+        Callback::from(move |_| {
+            let setup = SavedSetup {
+                state: (*state).clone(),
+                materials: (*materials).clone(),
+            };
+            match serde_json::to_string(&setup) {
+                Ok(json) => save_str("feeds-and-speeds.json", "application/json", &json),
+                Err(err) => log::error!("failed to serialize state: {err}"),
+            }
+        })
+    };
+
+    let on_export_csv = {
+        let state = state.clone();
+        let material = material.clone();
+        Callback::from(move |_| match state_to_csv(&state, &material) {
+            Ok(csv) => save_str("feeds-and-speeds.csv", "text/csv", &csv),
+            Err(err) => log::warn!("CSV-Export übersprungen: {err}"),
+        })
+    };
+
+    let on_import_click = {
+        let import_input_ref = import_input_ref.clone();
+        Callback::from(move |_| {
+            if let Some(input) = import_input_ref.cast::<HtmlInputElement>() {
+                input.click();
+            }
+        })
+    };
+
+    let on_import_file = {
+        let state = state.clone();
+        let materials = materials.clone();
+        Callback::from(move |event: Event| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let state = state.clone();
+                let materials = materials.clone();
+                let reader = FileReader::new().expect("failed to create FileReader");
+                let reader_for_onload = reader.clone();
+                let onload = Closure::once(move |_: Event| {
+                    let result = reader_for_onload.result().expect("read had no result");
+                    if let Some(text) = result.as_string() {
+                        match serde_json::from_str::<SavedSetup>(&text) {
+                            Ok(imported) => {
+                                state.set(imported.state);
+                                let materials_db = if imported.materials.materials.is_empty() {
+                                    MaterialDb::default()
+                                } else {
+                                    imported.materials
+                                };
+                                materials.set(materials_db);
+                            }
+                            Err(err) => log::error!("failed to parse imported setup: {err}"),
+                        }
+                    }
+                });
+                reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+                onload.forget();
+                reader
+                    .read_as_text(&file)
+                    .expect("failed to start reading file");
+            }
+            input.set_value("");
+        })
+    };
+
+    let rpm_range = state.rpm_range(&material);
     let min_machine_rpm = Step {
         value: state.min_rpm(),
         label: Some(Default::default()),
@@ -304,15 +748,20 @@ fn App() -> Html {
         },
     ];
 
-    let material_list = Material::iter()
-        .map(|value| html_nested! {<SelectOption<Material> {value}/>})
+    let material_list = materials
+        .materials
+        .iter()
+        .map(|spec| {
+            let value = spec.name.clone();
+            html_nested! {<SelectOption<String> {value}/>}
+        })
         .collect::<Vec<_>>();
     let variant = SelectVariant::Single(on_change_material);
     let chip = ChipVariant::Values;
     let selected_rpm = state.selected_rpm();
     let slide_pos = Some(selected_rpm).filter(|v| !v.is_nan());
     let diameter_str = format!("{:.2}", state.diameter());
-    let result = state.feed_range();
+    let result = state.feed_range(&material);
     let diameter_input_state = if state.diameter_error() {
         InputState::Error
     } else {
@@ -335,6 +784,13 @@ fn App() -> Html {
     } else {
         html!()
     };
+    let csv_export_error = if state.diameter_error() || state.flute_count_error() {
+        html! {
+          <Alert title={"CSV-Export nicht möglich: Eingaben fehlerhaft"}  r#type={Type::Warning}/>
+        }
+    } else {
+        html!()
+    };
     let result = if !(state.diameter_error() || state.flute_count_error()) {
         html! {
             <>
@@ -356,17 +812,18 @@ fn App() -> Html {
     let Range {
         start: vc_min,
         end: vc_max,
-    } = state.material().cut_speed();
+    } = material.cut_speed.clone();
     let Range {
         start: zf_min,
         end: zf_max,
-    } = feed_per_flute(*state.material(), state.diameter());
+    } = feed_per_flute(&material, state.diameter());
     html! {
                 <Card>
                     <FormGroup label="Material">
-                        <Select<Material> {variant} {chip} placeholder={state.material().to_string()}>
+                        <Select<String> {variant} {chip} placeholder={material.name.clone()}>
                             {material_list}
-                        </Select<Material>>
+                        </Select<String>>
+                        <Button label="Material entfernen" variant={ButtonVariant::Secondary} onclick={on_remove_material} disabled={materials.materials.len() <= 1}/>
                       <dl>
                         <dt>{"Schnittgeschwindigkeit"}</dt>
                         <dd class="value">{{format!("{vc_min:.0}-{vc_max:.0}")}}</dd>
@@ -403,6 +860,28 @@ fn App() -> Html {
                         <TextInput r#type="number" value={flute_count_str} onchange={on_change_flute_count} state={flute_count_input_state}/>
                     </FormGroup>
                     {result}
+                    <FormGroup label="Materialdatenbank">
+                        <TextInput placeholder="Name" value={(*new_material_name).clone()} onchange={on_new_material_name}/>
+                        <TextInput r#type="number" placeholder="Vc min" value={(*new_material_vc_min).clone()} onchange={on_new_material_vc_min}/>
+                        <TextInput r#type="number" placeholder="Vc max" value={(*new_material_vc_max).clone()} onchange={on_new_material_vc_max}/>
+                        {new_material_feed_rows_inputs}
+                        <Button label="Ausgewähltes Material laden" variant={ButtonVariant::Secondary} onclick={on_edit_material}/>
+                        <Button label="Material speichern" variant={ButtonVariant::Secondary} onclick={on_save_material} disabled={new_material_error}/>
+                    </FormGroup>
+                    <FormGroup label="Setup">
+                        <Button label="Export" variant={ButtonVariant::Secondary} onclick={on_export}/>
+                        <Button label="Import" variant={ButtonVariant::Secondary} onclick={on_import_click}/>
+                        <Button label="CSV-Export" variant={ButtonVariant::Secondary} onclick={on_export_csv}/>
+                        <Button label="Link kopieren" variant={ButtonVariant::Secondary} onclick={on_copy_link}/>
+                        <input
+                            ref={import_input_ref}
+                            type="file"
+                            accept=".json"
+                            style="display: none;"
+                            onchange={on_import_file}
+                        />
+                        {csv_export_error}
+                    </FormGroup>
                 </Card>
     }
 }